@@ -1,25 +1,48 @@
 use std::{
     alloc::{self, Layout},
-    cell::Cell,
+    cell::{Cell, RefCell},
     ops::{Deref, DerefMut},
     ptr::NonNull,
     rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
 };
 
+use smallvec::SmallVec;
+
 /// Store drop function ptr for types that `mem::needs_drop`
 struct DropHandler {
     value: NonNull<()>,
-    drop: unsafe fn(NonNull<()>),
+    len: usize,
+    drop: unsafe fn(NonNull<()>, usize),
 }
 
 impl DropHandler {
     fn new<T>(value: NonNull<T>) -> Self {
-        unsafe fn drop<T>(ptr: NonNull<()>) {
+        unsafe fn drop<T>(ptr: NonNull<()>, _len: usize) {
             std::ptr::drop_in_place(ptr.cast::<T>().as_ptr());
         }
 
         Self {
             value: value.cast(),
+            len: 0,
+            drop: drop::<T>,
+        }
+    }
+
+    /// Like [`DropHandler::new`], but drops `len` contiguous values starting
+    /// at `value` via a single `drop_in_place` of the whole `[T]`.
+    fn new_slice<T>(value: NonNull<T>, len: usize) -> Self {
+        unsafe fn drop<T>(ptr: NonNull<()>, len: usize) {
+            let slice = std::ptr::slice_from_raw_parts_mut(ptr.cast::<T>().as_ptr(), len);
+            std::ptr::drop_in_place(slice);
+        }
+
+        Self {
+            value: value.cast(),
+            len,
             drop: drop::<T>,
         }
     }
@@ -27,10 +50,16 @@ impl DropHandler {
 
 impl Drop for DropHandler {
     fn drop(&mut self) {
-        unsafe { (self.drop)(self.value) };
+        unsafe { (self.drop)(self.value, self.len) };
     }
 }
 
+// SAFETY: `DropHandler` only ever points into an `AllocationPage` that it
+// does not otherwise alias, and it is only ever driven by the arena that
+// owns it. Sending it to another thread is sound as long as access to the
+// arena's pages is synchronized, which `SyncWeakArena` does via its `Mutex`.
+unsafe impl Send for DropHandler {}
+
 struct AllocationPage {
     layout: Layout,
     start: NonNull<u8>,
@@ -81,11 +110,65 @@ impl Drop for AllocationPage {
     }
 }
 
+// SAFETY: `AllocationPage` exclusively owns its backing allocation; the raw
+// pointers it stores never alias foreign memory, so moving/sharing a page
+// across threads is sound as long as access to it is otherwise synchronized
+// (as `SyncWeakArena` does via its `Mutex`).
+unsafe impl Send for AllocationPage {}
+
 struct Cursor {
     page: usize,
     offset: NonNull<u8>,
 }
 
+// SAFETY: see `AllocationPage`'s `Send` impl above; a `Cursor` is just a
+// page index plus a pointer into one of the arena's own pages.
+unsafe impl Send for Cursor {}
+
+/// Bump-allocates `layout` out of the current page, growing a fresh page if it doesn't fit.
+fn bump_alloc(
+    page_size: &mut usize,
+    pages: &mut Vec<AllocationPage>,
+    cursor: &mut Cursor,
+    layout: Layout,
+) -> NonNull<u8> {
+    if let Some((data_ptr, data_end_ptr)) = pages
+        .get_mut(cursor.page)
+        .and_then(|page| page.try_alloc_layout(cursor.offset, layout))
+    {
+        cursor.offset = data_end_ptr;
+        return data_ptr;
+    }
+
+    // Each page twice as big as previous (like Vec)
+    *page_size *= 2;
+    // If page size is to small, let's just allocate as much as we need
+    *page_size = (*page_size).max(layout.size());
+
+    let mut page = AllocationPage::new(*page_size);
+    let (data_ptr, data_end_ptr) = page.try_alloc_layout(page.start, layout).unwrap();
+
+    let id = pages.len();
+    pages.push(page);
+    cursor.page = id;
+    cursor.offset = data_end_ptr;
+
+    data_ptr
+}
+
+/// A checkpoint captured by [`WeakArena::mark`] for [`WeakArena::reset_to`].
+pub struct ArenaMark {
+    page: usize,
+    offset: NonNull<u8>,
+    page_size: usize,
+    drop_handlers_len: usize,
+    // Index into the owning arena's `generations` stack, plus the cell that
+    // lived there, so `reset_to` can tell a stale/foreign mark apart via
+    // `Rc::ptr_eq`.
+    index: usize,
+    cell: Rc<Cell<bool>>,
+}
+
 pub struct WeakArena {
     page_size: usize,
     // TODO: This Vec introduces extra allocation, that could be part of the page allocation itself
@@ -94,7 +177,9 @@ pub struct WeakArena {
     cursor: Cursor,
     drop_handlers: Vec<DropHandler>,
 
-    alive: Rc<Cell<bool>>,
+    // One liveness cell per currently open `mark()`, plus a base cell.
+    // New allocations are stamped with a clone of the top cell.
+    generations: Vec<Rc<Cell<bool>>>,
 }
 
 impl Drop for WeakArena {
@@ -115,10 +200,17 @@ impl WeakArena {
             },
             pages: vec![page],
             drop_handlers: Vec::new(),
-            alive: Rc::new(Cell::new(true)),
+            generations: vec![Rc::new(Cell::new(true))],
         }
     }
 
+    fn current_generation(&self) -> Rc<Cell<bool>> {
+        self.generations
+            .last()
+            .expect("WeakArena always has at least one generation")
+            .clone()
+    }
+
     pub fn clear(&mut self) {
         // This will call all `Drop::drop` functions
         self.drop_handlers.clear();
@@ -128,8 +220,10 @@ impl WeakArena {
             self.pages.drain(0..self.pages.len() - 1);
         }
 
-        self.alive.set(false);
-        self.alive = Rc::new(Cell::new(true));
+        for cell in &self.generations {
+            cell.set(false);
+        }
+        self.generations = vec![Rc::new(Cell::new(true))];
 
         self.cursor = Cursor {
             page: 0,
@@ -137,6 +231,57 @@ impl WeakArena {
         };
     }
 
+    /// Captures the arena's current allocation position for [`WeakArena::reset_to`].
+    ///
+    /// Takes `&mut self` rather than `&self`, since it pushes a new liveness
+    /// cell onto `generations` so `reset_to` can scope invalidation to the
+    /// marked region instead of the whole arena.
+    pub fn mark(&mut self) -> ArenaMark {
+        let cell = Rc::new(Cell::new(true));
+        self.generations.push(cell.clone());
+
+        ArenaMark {
+            page: self.cursor.page,
+            offset: self.cursor.offset,
+            page_size: self.page_size,
+            drop_handlers_len: self.drop_handlers.len(),
+            index: self.generations.len() - 1,
+            cell,
+        }
+    }
+
+    /// Rewinds the arena to a checkpoint from [`WeakArena::mark`], discarding
+    /// everything allocated since without touching what came before it.
+    pub fn reset_to(&mut self, mark: ArenaMark) {
+        assert!(
+            self.generations
+                .get(mark.index)
+                .is_some_and(|cell| Rc::ptr_eq(cell, &mark.cell)),
+            "ArenaMark is stale, or was taken from a different WeakArena"
+        );
+
+        // Kill every generation from the mark's depth onward; the rest stay alive.
+        for cell in &self.generations[mark.index..] {
+            cell.set(false);
+        }
+        self.generations.truncate(mark.index);
+        self.generations.push(Rc::new(Cell::new(true)));
+
+        // Run the drop handlers pushed after the mark, in LIFO order.
+        while self.drop_handlers.len() > mark.drop_handlers_len {
+            self.drop_handlers.pop();
+        }
+
+        // Pages allocated after the mark are part of the discarded region.
+        self.pages.truncate(mark.page + 1);
+        self.page_size = mark.page_size;
+
+        self.cursor = Cursor {
+            page: mark.page,
+            offset: mark.offset,
+        };
+    }
+
     pub fn alloc<T>(&mut self, v: T) -> WeakBox<T> {
         self.alloc_with(|| v)
     }
@@ -158,63 +303,433 @@ impl WeakArena {
             self.drop_handlers.push(DropHandler::new(data_ptr));
         }
 
-        WeakBox::new(data_ptr, self.alive.clone())
+        WeakBox::new(data_ptr, self.current_generation())
     }
 
-    #[inline(always)]
-    fn alloc_in_current_page(&mut self, layout: Layout) -> Option<NonNull<u8>> {
-        let (data_ptr, data_end_ptr) = self
-            .pages
-            .get_mut(self.cursor.page)?
-            .try_alloc_layout(self.cursor.offset, layout)?;
+    fn alloc_layout(&mut self, layout: Layout) -> NonNull<u8> {
+        bump_alloc(&mut self.page_size, &mut self.pages, &mut self.cursor, layout)
+    }
 
-        self.cursor.offset = data_end_ptr;
+    /// Copies `s` into the arena and returns a [`WeakBox`] over the copy.
+    pub fn alloc_slice_copy<T: Copy>(&mut self, s: &[T]) -> WeakBox<[T]> {
+        let len = s.len();
+        let data_ptr = self.alloc_slice_layout::<T>(len);
 
-        Some(data_ptr)
+        unsafe { std::ptr::copy_nonoverlapping(s.as_ptr(), data_ptr.as_ptr(), len) };
+
+        self.finish_slice_alloc(data_ptr, len)
     }
 
-    #[inline(always)]
-    fn alloc_in_new_page(&mut self, layout: Layout) -> NonNull<u8> {
-        // Each page twice as big as previous (like Vec)
-        self.page_size *= 2;
-        // If page size is to small, let's just allocate as much as we need
-        self.page_size = self.page_size.max(layout.size());
+    /// Clones each element of `s` into the arena and returns a [`WeakBox`] over the clones.
+    pub fn alloc_slice_clone<T: Clone>(&mut self, s: &[T]) -> WeakBox<[T]> {
+        let len = s.len();
+        let data_ptr = self.alloc_slice_layout::<T>(len);
+        let needs_drop = std::mem::needs_drop::<T>();
+
+        // Register each clone's drop handler as soon as it's written, so a
+        // panic below doesn't leak the already-written prefix.
+        for (i, v) in s.iter().enumerate() {
+            let elem_ptr = unsafe { NonNull::new_unchecked(data_ptr.as_ptr().add(i)) };
+            unsafe { elem_ptr.as_ptr().write(v.clone()) };
+            if needs_drop {
+                self.drop_handlers.push(DropHandler::new(elem_ptr));
+            }
+        }
+
+        WeakBox::new(NonNull::slice_from_raw_parts(data_ptr, len), self.current_generation())
+    }
+
+    /// Allocates a contiguous `[T]` from an iterator.
+    pub fn alloc_from_iter<T, I>(&mut self, iter: I) -> WeakBox<[T]>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => {
+                let data_ptr = self.alloc_slice_layout::<T>(lower);
+                let needs_drop = std::mem::needs_drop::<T>();
+
+                // Register each element's drop handler as soon as it's written,
+                // so a panic below doesn't leak the already-written prefix.
+                for i in 0..lower {
+                    let v = iter
+                        .next()
+                        .expect("iterator yielded fewer elements than its reported length");
+                    let elem_ptr = unsafe { NonNull::new_unchecked(data_ptr.as_ptr().add(i)) };
+                    unsafe { elem_ptr.as_ptr().write(v) };
+                    if needs_drop {
+                        self.drop_handlers.push(DropHandler::new(elem_ptr));
+                    }
+                }
+                assert!(
+                    iter.next().is_none(),
+                    "iterator yielded more elements than its reported length"
+                );
+
+                WeakBox::new(
+                    NonNull::slice_from_raw_parts(data_ptr, lower),
+                    self.current_generation(),
+                )
+            }
+            _ => {
+                let mut values: SmallVec<[T; 8]> = iter.collect();
+                let len = values.len();
+                let data_ptr = self.alloc_slice_layout::<T>(len);
+
+                unsafe {
+                    std::ptr::copy_nonoverlapping(values.as_ptr(), data_ptr.as_ptr(), len);
+                    // Ownership of the elements has moved into the arena; drop
+                    // `values` as empty so it only frees its own backing
+                    // storage without dropping the (now arena-owned) values.
+                    values.set_len(0);
+                }
+
+                self.finish_slice_alloc(data_ptr, len)
+            }
+        }
+    }
+
+    /// Copies `s` into the arena and returns a [`WeakBox`] over the copy, reinterpreted as `str`.
+    pub fn alloc_str(&mut self, s: &str) -> WeakBox<str> {
+        let bytes = s.as_bytes();
+        let len = bytes.len();
+        let data_ptr = self.alloc_slice_layout::<u8>(len);
+
+        unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), data_ptr.as_ptr(), len) };
+
+        let slice = unsafe { std::slice::from_raw_parts(data_ptr.as_ptr(), len) };
+        let str_ref = unsafe { std::str::from_utf8_unchecked(slice) };
+        let str_ptr = unsafe { NonNull::new_unchecked(str_ref as *const str as *mut str) };
 
-        let mut page = AllocationPage::new(self.page_size);
-        let (data_ptr, data_end_ptr) = page.try_alloc_layout(page.start, layout).unwrap();
+        WeakBox::new(str_ptr, self.current_generation())
+    }
+
+    /// Reserves room for `len` contiguous `T`s and returns a pointer to the start.
+    fn alloc_slice_layout<T>(&mut self, len: usize) -> NonNull<T> {
+        if len == 0 {
+            return NonNull::dangling();
+        }
+
+        let layout = Layout::array::<T>(len).unwrap();
+        self.alloc_layout(layout).cast::<T>()
+    }
+
+    /// Registers a drop handler (if needed) and wraps `data_ptr`/`len` as a `WeakBox<[T]>`.
+    fn finish_slice_alloc<T>(&mut self, data_ptr: NonNull<T>, len: usize) -> WeakBox<[T]> {
+        if std::mem::needs_drop::<T>() {
+            self.drop_handlers.push(DropHandler::new_slice(data_ptr, len));
+        }
+
+        WeakBox::new(NonNull::slice_from_raw_parts(data_ptr, len), self.current_generation())
+    }
+}
+
+/// An arena that only hands out `T: Copy` values, and can therefore allocate
+/// through `&self` instead of `&mut self`.
+pub struct DroplessArena {
+    page_size: Cell<usize>,
+    // TODO: This Vec introduces extra allocation, that could be part of the page allocation itself
+    pages: RefCell<Vec<AllocationPage>>,
+
+    current_page: Cell<usize>,
+    cursor: Cell<NonNull<u8>>,
+
+    // Current liveness cell; `clear()` kills it and swaps in a fresh one.
+    generation: Rc<Cell<bool>>,
+}
+
+impl Drop for DroplessArena {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl DroplessArena {
+    pub fn new(page_size: usize) -> Self {
+        let page = AllocationPage::new(page_size);
+        let cursor = page.start;
+
+        Self {
+            page_size: Cell::new(page_size),
+            current_page: Cell::new(0),
+            cursor: Cell::new(cursor),
+            pages: RefCell::new(vec![page]),
+            generation: Rc::new(Cell::new(true)),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        let mut pages = self.pages.borrow_mut();
+
+        // Deallocate all pages except the last one
+        if pages.len() > 1 {
+            let len = pages.len();
+            pages.drain(0..len - 1);
+        }
 
-        let id = self.pages.len();
-        self.pages.push(page);
-        self.cursor.page = id;
-        self.cursor.offset = data_end_ptr;
+        self.generation.set(false);
+        self.generation = Rc::new(Cell::new(true));
+
+        self.current_page.set(0);
+        self.cursor.set(pages[0].start);
+    }
+
+    pub fn alloc<T: Copy>(&self, v: T) -> WeakBox<T> {
+        let data_ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+
+        unsafe { data_ptr.as_ptr().write(v) };
+
+        WeakBox::new(data_ptr, self.generation.clone())
+    }
+
+    pub fn alloc_slice<T: Copy>(&self, s: &[T]) -> WeakBox<[T]> {
+        let len = s.len();
+
+        if len == 0 {
+            let ptr = NonNull::slice_from_raw_parts(NonNull::dangling(), 0);
+            return WeakBox::new(ptr, self.generation.clone());
+        }
+
+        let layout = Layout::array::<T>(len).unwrap();
+        let data_ptr = self.alloc_layout(layout).cast::<T>();
+
+        unsafe { std::ptr::copy_nonoverlapping(s.as_ptr(), data_ptr.as_ptr(), len) };
+
+        WeakBox::new(NonNull::slice_from_raw_parts(data_ptr, len), self.generation.clone())
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let mut page_size = self.page_size.get();
+        let mut pages = self.pages.borrow_mut();
+        let mut cursor = Cursor {
+            page: self.current_page.get(),
+            offset: self.cursor.get(),
+        };
+
+        let data_ptr = bump_alloc(&mut page_size, &mut pages, &mut cursor, layout);
+
+        self.page_size.set(page_size);
+        self.current_page.set(cursor.page);
+        self.cursor.set(cursor.offset);
 
         data_ptr
     }
+}
 
-    fn alloc_layout(&mut self, layout: Layout) -> NonNull<u8> {
-        self.alloc_in_current_page(layout)
-            .unwrap_or_else(|| self.alloc_in_new_page(layout))
+struct SyncArenaState {
+    page_size: usize,
+    // TODO: This Vec introduces extra allocation, that could be part of the page allocation itself
+    pages: Vec<AllocationPage>,
+
+    cursor: Cursor,
+    drop_handlers: Vec<DropHandler>,
+}
+
+/// A [`WeakArena`] that can be filled from multiple threads at once.
+pub struct SyncWeakArena {
+    state: Mutex<SyncArenaState>,
+    alive: Arc<AtomicBool>,
+}
+
+impl Drop for SyncWeakArena {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl SyncWeakArena {
+    pub fn new(page_size: usize) -> Self {
+        let page = AllocationPage::new(page_size);
+        let cursor = Cursor {
+            page: 0,
+            offset: page.start,
+        };
+
+        Self {
+            state: Mutex::new(SyncArenaState {
+                page_size,
+                pages: vec![page],
+                cursor,
+                drop_handlers: Vec::new(),
+            }),
+            alive: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        let state = self.state.get_mut().unwrap();
+
+        // This will call all `Drop::drop` functions
+        state.drop_handlers.clear();
+
+        // Deallocate all pages except the last one
+        if state.pages.len() > 1 {
+            let len = state.pages.len();
+            state.pages.drain(0..len - 1);
+        }
+
+        self.alive.store(false, Ordering::SeqCst);
+        self.alive = Arc::new(AtomicBool::new(true));
+
+        state.cursor = Cursor {
+            page: 0,
+            offset: state.pages[0].start,
+        };
+    }
+
+    pub fn alloc<T: Send>(&self, v: T) -> SyncWeakBox<T> {
+        self.alloc_with(|| v)
+    }
+
+    pub fn alloc_with<T: Send>(&self, f: impl FnOnce() -> T) -> SyncWeakBox<T> {
+        let mut state = self.state.lock().unwrap();
+
+        let data_ptr = Self::alloc_layout_locked(&mut state, Layout::new::<T>()).cast::<T>();
+
+        unsafe { data_ptr.as_ptr().write(f()) };
+
+        if std::mem::needs_drop::<T>() {
+            state.drop_handlers.push(DropHandler::new(data_ptr));
+        }
+
+        SyncWeakBox::new(data_ptr, self.alive.clone())
+    }
+
+    fn alloc_layout_locked(state: &mut SyncArenaState, layout: Layout) -> NonNull<u8> {
+        bump_alloc(&mut state.page_size, &mut state.pages, &mut state.cursor, layout)
+    }
+}
+
+pub struct SyncWeakBox<T: Send> {
+    ptr: NonNull<T>,
+    alive: Arc<AtomicBool>,
+}
+
+// SAFETY: a `SyncWeakBox<T>` behaves like a `Box<T>` into arena memory: only
+// one handle is ever live for a given allocation, so moving it to another
+// thread is sound exactly when `T` itself is `Send`.
+unsafe impl<T: Send> Send for SyncWeakBox<T> {}
+
+impl<T: Send> SyncWeakBox<T> {
+    pub fn new(ptr: NonNull<T>, alive: Arc<AtomicBool>) -> Self {
+        Self { ptr, alive }
+    }
+
+    #[inline]
+    pub fn as_ref(&self) -> Option<&T> {
+        self.alive
+            .load(Ordering::Acquire)
+            .then_some(unsafe { self.ptr.as_ref() })
+    }
+
+    #[inline]
+    pub fn as_mut(&mut self) -> Option<&mut T> {
+        self.alive
+            .load(Ordering::Acquire)
+            .then_some(unsafe { self.ptr.as_mut() })
+    }
+
+    pub fn into_shared(self) -> SyncWeakShared<T>
+    where
+        T: Sync,
+    {
+        SyncWeakShared {
+            ptr: self.ptr,
+            alive: self.alive,
+        }
+    }
+}
+
+impl<T: Send> Deref for SyncWeakBox<T> {
+    type Target = T;
+
+    #[inline(always)]
+    #[track_caller]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref().expect("Dead resource")
+    }
+}
+
+impl<T: Send> DerefMut for SyncWeakBox<T> {
+    #[inline(always)]
+    #[track_caller]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut().expect("Dead resource")
+    }
+}
+
+pub struct SyncWeakShared<T: Sync> {
+    ptr: NonNull<T>,
+    alive: Arc<AtomicBool>,
+}
+
+// SAFETY: a `SyncWeakShared<T>` never runs `T`'s destructor (the arena's
+// drop handlers do that on `clear()`), so sending or sharing the handle
+// itself across threads only requires concurrent read access to `T`, i.e.
+// `T: Sync`.
+unsafe impl<T: Sync> Send for SyncWeakShared<T> {}
+unsafe impl<T: Sync> Sync for SyncWeakShared<T> {}
+
+impl<T: Sync> SyncWeakShared<T> {
+    pub fn new(ptr: NonNull<T>, alive: Arc<AtomicBool>) -> Self {
+        Self { ptr, alive }
+    }
+
+    #[inline]
+    pub fn as_ref(&self) -> Option<&T> {
+        self.alive
+            .load(Ordering::Acquire)
+            .then_some(unsafe { self.ptr.as_ref() })
+    }
+}
+
+impl<T: Sync> Deref for SyncWeakShared<T> {
+    type Target = T;
+
+    #[inline(always)]
+    #[track_caller]
+    fn deref(&self) -> &Self::Target {
+        self.as_ref().expect("Dead resource")
     }
 }
 
-pub struct WeakBox<T> {
+// Clonable because mut access is not possible for Shared
+impl<T: Sync> Clone for SyncWeakShared<T> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            alive: self.alive.clone(),
+        }
+    }
+}
+
+pub struct WeakBox<T: ?Sized> {
     ptr: NonNull<T>,
+    // The generation cell this value was allocated under - the value is
+    // alive iff the cell still reads `true`.
     alive: Rc<Cell<bool>>,
 }
 
-impl<T> WeakBox<T> {
+impl<T: ?Sized> WeakBox<T> {
     pub fn new(ptr: NonNull<T>, alive: Rc<Cell<bool>>) -> Self {
         Self { ptr, alive }
     }
 
+    #[inline]
+    fn is_alive(&self) -> bool {
+        self.alive.get()
+    }
+
     #[inline]
     pub fn as_ref(&self) -> Option<&T> {
-        self.alive.get().then_some(unsafe { self.ptr.as_ref() })
+        self.is_alive().then_some(unsafe { self.ptr.as_ref() })
     }
 
     #[inline]
     pub fn as_mut(&mut self) -> Option<&mut T> {
-        self.alive.get().then_some(unsafe { self.ptr.as_mut() })
+        self.is_alive().then_some(unsafe { self.ptr.as_mut() })
     }
 
     pub fn into_shared(self) -> WeakShared<T> {
@@ -225,7 +740,7 @@ impl<T> WeakBox<T> {
     }
 }
 
-impl<T> Deref for WeakBox<T> {
+impl<T: ?Sized> Deref for WeakBox<T> {
     type Target = T;
 
     #[inline(always)]
@@ -235,7 +750,7 @@ impl<T> Deref for WeakBox<T> {
     }
 }
 
-impl<T> DerefMut for WeakBox<T> {
+impl<T: ?Sized> DerefMut for WeakBox<T> {
     #[inline(always)]
     #[track_caller]
     fn deref_mut(&mut self) -> &mut Self::Target {
@@ -243,12 +758,12 @@ impl<T> DerefMut for WeakBox<T> {
     }
 }
 
-pub struct WeakShared<T> {
+pub struct WeakShared<T: ?Sized> {
     ptr: NonNull<T>,
     alive: Rc<Cell<bool>>,
 }
 
-impl<T> WeakShared<T> {
+impl<T: ?Sized> WeakShared<T> {
     pub fn new(ptr: NonNull<T>, alive: Rc<Cell<bool>>) -> Self {
         Self { ptr, alive }
     }
@@ -259,7 +774,7 @@ impl<T> WeakShared<T> {
     }
 }
 
-impl<T> Deref for WeakShared<T> {
+impl<T: ?Sized> Deref for WeakShared<T> {
     type Target = T;
 
     #[inline(always)]
@@ -270,7 +785,7 @@ impl<T> Deref for WeakShared<T> {
 }
 
 // Clonable because mut access is not possible for Shared
-impl<T> Clone for WeakShared<T> {
+impl<T: ?Sized> Clone for WeakShared<T> {
     fn clone(&self) -> Self {
         Self {
             ptr: self.ptr,
@@ -355,4 +870,293 @@ mod tests {
 
         assert!(a.as_ref().is_none());
     }
+
+    #[test]
+    fn alloc_slice_copy_is_contiguous() {
+        let mut arena = WeakArena::new(64);
+
+        let s = arena.alloc_slice_copy(&[1, 2, 3, 4]);
+        assert_eq!(&*s, &[1, 2, 3, 4]);
+
+        arena.clear();
+        assert!(s.as_ref().is_none());
+    }
+
+    #[test]
+    fn alloc_slice_clone_clones_elements() {
+        let mut arena = WeakArena::new(64);
+
+        let s = arena.alloc_slice_clone(&[String::from("a"), String::from("b")]);
+        assert_eq!(&*s, &[String::from("a"), String::from("b")]);
+    }
+
+    #[test]
+    fn alloc_slice_clone_drops_the_written_prefix_if_clone_panics() {
+        struct RecordDrop(Rc<RefCell<Vec<i32>>>, i32);
+        impl Drop for RecordDrop {
+            fn drop(&mut self) {
+                self.0.borrow_mut().push(self.1);
+            }
+        }
+        impl Clone for RecordDrop {
+            fn clone(&self) -> Self {
+                assert!(self.1 != 3, "clone panics on the 3rd element");
+                Self(self.0.clone(), self.1)
+            }
+        }
+
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+        let mut arena = WeakArena::new(64);
+
+        let values = [1, 2, 3, 4].map(|n| RecordDrop(dropped.clone(), n));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            arena.alloc_slice_clone(&values)
+        }));
+        assert!(result.is_err());
+
+        // The 2 already-cloned arena copies must still be dropped once the
+        // arena goes away, even though the panic happened before
+        // `alloc_slice_clone` could finish.
+        drop(arena);
+        assert_eq!(*dropped.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn alloc_from_iter_exact_and_unbounded() {
+        let mut arena = WeakArena::new(64);
+
+        let exact = arena.alloc_from_iter(0..4);
+        assert_eq!(&*exact, &[0, 1, 2, 3]);
+
+        let filtered = arena.alloc_from_iter((0..10).filter(|n| n % 2 == 0));
+        assert_eq!(&*filtered, &[0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn alloc_from_iter_drops_the_written_prefix_if_the_exact_size_hint_lies() {
+        struct LyingIter(Rc<RefCell<Vec<i32>>>, std::vec::IntoIter<i32>);
+
+        impl Iterator for LyingIter {
+            type Item = RecordDrop;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.1.next().map(|v| RecordDrop(self.0.clone(), v))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                // Reports one more element than it will actually yield.
+                let (lower, upper) = self.1.size_hint();
+                (lower + 1, upper.map(|u| u + 1))
+            }
+        }
+
+        impl ExactSizeIterator for LyingIter {}
+
+        struct RecordDrop(Rc<RefCell<Vec<i32>>>, i32);
+        impl Drop for RecordDrop {
+            fn drop(&mut self) {
+                self.0.borrow_mut().push(self.1);
+            }
+        }
+
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+        let mut arena = WeakArena::new(64);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            arena.alloc_from_iter(LyingIter(dropped.clone(), vec![1, 2, 3].into_iter()))
+        }));
+        assert!(result.is_err());
+
+        // The 3 already-written elements must still be dropped once the
+        // arena goes away, even though the panic happened before
+        // `alloc_from_iter` could finish.
+        drop(arena);
+        assert_eq!(*dropped.borrow(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn alloc_str_reports_dead_after_clear() {
+        let mut arena = WeakArena::new(64);
+
+        let s = arena.alloc_str("hello");
+        assert_eq!(&*s, "hello");
+
+        arena.clear();
+        assert!(s.as_ref().is_none());
+    }
+
+    #[test]
+    fn dropless_arena_allocates_through_shared_ref() {
+        let arena = DroplessArena::new(std::mem::size_of::<i32>());
+
+        let a = arena.alloc(10);
+        let b = arena.alloc(20);
+        let s = arena.alloc_slice(&[1, 2, 3]);
+
+        assert_eq!(*a, 10);
+        assert_eq!(*b, 20);
+        assert_eq!(&*s, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn generation_only_kills_handles_from_before_the_clear() {
+        let mut arena = WeakArena::new(std::mem::size_of::<i32>());
+
+        let before = arena.alloc(1);
+        arena.clear();
+        let after = arena.alloc(2);
+
+        assert!(before.as_ref().is_none());
+        assert_eq!(after.as_ref(), Some(&2));
+    }
+
+    #[test]
+    fn reset_to_rewinds_cursor_and_runs_drop_handlers_lifo() {
+        let mut arena = WeakArena::new(64);
+
+        let order = Rc::new(RefCell::new(Vec::new()));
+
+        struct RecordDrop(Rc<RefCell<Vec<i32>>>, i32);
+        impl Drop for RecordDrop {
+            fn drop(&mut self) {
+                self.0.borrow_mut().push(self.1);
+            }
+        }
+
+        let mark = arena.mark();
+        let (mark_page, mark_offset) = (arena.cursor.page, arena.cursor.offset);
+
+        arena.alloc(RecordDrop(order.clone(), 1));
+        arena.alloc(RecordDrop(order.clone(), 2));
+        arena.alloc(RecordDrop(order.clone(), 3));
+
+        arena.reset_to(mark);
+
+        assert_eq!(arena.cursor.page, mark_page);
+        assert_eq!(arena.cursor.offset, mark_offset);
+        assert_eq!(*order.borrow(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn reset_to_allows_reallocating_the_discarded_region() {
+        let mut arena = WeakArena::new(std::mem::size_of::<i32>());
+
+        let mark = arena.mark();
+        for i in 0..4 {
+            arena.alloc(i);
+        }
+        // Pages created after the mark are part of the discarded region.
+        assert_eq!(arena.pages.len(), 3);
+
+        arena.reset_to(mark);
+        assert_eq!(arena.pages.len(), 1);
+
+        let values: Vec<i32> = (0..4).map(|i| *arena.alloc(i)).collect();
+        assert_eq!(values, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "stale")]
+    fn reset_to_rejects_a_mark_from_before_a_clear() {
+        let mut arena = WeakArena::new(std::mem::size_of::<i32>());
+
+        let mark = arena.mark();
+        arena.clear();
+        arena.reset_to(mark);
+    }
+
+    #[test]
+    #[should_panic(expected = "different WeakArena")]
+    fn reset_to_rejects_a_mark_from_a_different_arena() {
+        let mut arena_a = WeakArena::new(std::mem::size_of::<i32>());
+        let mut arena_b = WeakArena::new(std::mem::size_of::<i32>());
+
+        let mark = arena_a.mark();
+        arena_b.reset_to(mark);
+    }
+
+    #[test]
+    fn reset_to_only_kills_handles_allocated_after_the_mark() {
+        let mut arena = WeakArena::new(std::mem::size_of::<i32>());
+
+        let early = arena.alloc(1);
+        let mark = arena.mark();
+        let speculative = arena.alloc(2);
+
+        arena.reset_to(mark);
+
+        assert_eq!(early.as_ref(), Some(&1));
+        assert!(speculative.as_ref().is_none());
+    }
+
+    #[test]
+    fn reset_to_does_not_affect_handles_from_a_prior_committed_mark() {
+        let mut arena = WeakArena::new(std::mem::size_of::<i32>());
+
+        // `_outer_mark` is never reset - the speculative branch succeeded.
+        let _outer_mark = arena.mark();
+        let kept = arena.alloc(1);
+
+        let inner_mark = arena.mark();
+        let discarded = arena.alloc(2);
+        arena.reset_to(inner_mark);
+
+        assert_eq!(kept.as_ref(), Some(&1));
+        assert!(discarded.as_ref().is_none());
+    }
+
+    #[test]
+    fn reset_to_does_not_leak_page_size_growth_across_cycles() {
+        let mut arena = WeakArena::new(std::mem::size_of::<i32>());
+
+        for _ in 0..5 {
+            let mark = arena.mark();
+            for i in 0..8 {
+                arena.alloc(i);
+            }
+            arena.reset_to(mark);
+        }
+
+        assert_eq!(arena.page_size, std::mem::size_of::<i32>());
+    }
+
+    #[test]
+    fn sync_weak_arena_fills_from_multiple_threads() {
+        let arena = Arc::new(SyncWeakArena::new(std::mem::size_of::<i32>()));
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let arena = arena.clone();
+                std::thread::spawn(move || arena.alloc(i))
+            })
+            .collect();
+
+        let mut values: Vec<i32> = handles
+            .into_iter()
+            .map(|h| *h.join().unwrap())
+            .collect();
+        values.sort();
+
+        assert_eq!(values, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sync_weak_arena_clear_invalidates_handles() {
+        let mut arena = SyncWeakArena::new(std::mem::size_of::<i32>());
+
+        let a = arena.alloc(10);
+        arena.clear();
+
+        assert!(a.as_ref().is_none());
+    }
+
+    #[test]
+    fn dropless_arena_clear_invalidates_handles() {
+        let mut arena = DroplessArena::new(std::mem::size_of::<i32>());
+
+        let a = arena.alloc(10);
+        arena.clear();
+
+        assert!(a.as_ref().is_none());
+    }
 }